@@ -9,6 +9,12 @@
 #[cfg(test)]
 extern crate alloc;
 
+// Referenced only so `forbid(unused_crate_dependencies)` doesn't flag it: it's a
+// direct dependency purely to turn on its own `zeroize` feature (wired up in
+// Cargo.toml), which is what makes `digest::Output::zeroize()` below available.
+#[cfg(feature = "zeroize")]
+use generic_array as _;
+
 /// CSPRNG that takes any hashable data as the seed
 ///
 /// See [crate] docs for more details.
@@ -43,6 +49,156 @@ impl<D: digest::Digest, S: udigest::Digestable> HashRng<D, S> {
             seed: &self.seed,
         }));
     }
+
+    /// Returns the current absolute byte position in the randomness stream
+    pub fn position(&self) -> u64 {
+        (self.counter - 1) * self.buffer.len() as u64 + self.offset as u64
+    }
+
+    /// Seeks to the given absolute byte position in the randomness stream
+    ///
+    /// As the output is a pure function of the counter, this jumps directly to the
+    /// block containing `pos` rather than generating and discarding the bytes before it.
+    pub fn set_position(&mut self, pos: u64) {
+        let out_len = self.buffer.len() as u64;
+        let block = pos / out_len;
+        let within = pos % out_len;
+
+        self.counter = block;
+        self.advance_buffer();
+        self.offset = within as usize;
+    }
+
+    /// Derives an independent child generator, domain-separated by `label`
+    ///
+    /// Forks with different `label`s produce independent streams, and a fork's
+    /// output never collides with the parent's own stream. The child's seed is
+    /// wrapped in [`udigest::Bytes`], since the derived digest output isn't itself
+    /// `Digestable`.
+    pub fn fork(&self, label: impl udigest::Digestable) -> HashRng<D, udigest::Bytes<digest::Output<D>>> {
+        let child_seed = udigest::hash::<D>(&udigest::inline_struct!("dfns.rand_hash.fork" {
+            parent_seed: &self.seed,
+            position: self.position(),
+            label: label,
+        }));
+        HashRng::from_seed(udigest::Bytes(child_seed))
+    }
+}
+
+impl<D: digest::Digest> HashRng<D, udigest::Bytes<digest::Output<D>>> {
+    /// Mixes fresh entropy into the generator, re-deriving its internal seed
+    ///
+    /// Subsequent output is a function of both the original seed and the absorbed
+    /// `fresh` data. Only available on a byte-seeded generator, i.e. one produced by
+    /// [`fork`](HashRng::fork); a `HashRng<D, S>` for another `S` (including the
+    /// `[u8; 32]`-seeded generator from `SeedableRng`) has no way to derive this
+    /// seed shape and must go through `fork` first.
+    pub fn mix(&mut self, fresh: impl udigest::Digestable) {
+        self.seed = udigest::Bytes(udigest::hash::<D>(&udigest::inline_struct!(
+            "dfns.rand_hash.reseed" {
+                old_seed: &self.seed,
+                current_buffer: udigest::Bytes(self.buffer.as_slice()),
+                position: self.position(),
+                fresh: fresh,
+            }
+        )));
+        self.counter = 0;
+        self.advance_buffer();
+    }
+}
+
+/// Wraps [`HashRng`] and automatically [mixes](HashRng::mix) in fresh entropy from
+/// an entropy source once a configurable number of bytes has been emitted
+pub struct ReseedingHashRng<D: digest::Digest, R> {
+    rng: HashRng<D, udigest::Bytes<digest::Output<D>>>,
+    entropy_source: R,
+    threshold: u64,
+    emitted_since_reseed: u64,
+}
+
+impl<D: digest::Digest, R: rand_core::RngCore> ReseedingHashRng<D, R> {
+    /// Wraps `rng`, mixing in entropy from `entropy_source` every `threshold` bytes
+    pub fn new(rng: HashRng<D, udigest::Bytes<digest::Output<D>>>, entropy_source: R, threshold: u64) -> Self {
+        Self {
+            rng,
+            entropy_source,
+            threshold,
+            emitted_since_reseed: 0,
+        }
+    }
+
+    fn reseed_if_needed(&mut self) {
+        if self.emitted_since_reseed >= self.threshold {
+            let mut fresh = digest::Output::<D>::default();
+            self.entropy_source.fill_bytes(&mut fresh);
+            self.rng.mix(udigest::Bytes(fresh));
+            self.emitted_since_reseed = 0;
+        }
+    }
+}
+
+impl<D: digest::Digest, R: rand_core::RngCore> rand_core::RngCore for ReseedingHashRng<D, R> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // Reseed at every threshold crossing, not just once per call, so a single
+        // large read can't emit more than `threshold` bytes past the last reseed.
+        let mut written = 0;
+        while written < dest.len() {
+            self.reseed_if_needed();
+
+            let until_next_reseed = (self.threshold - self.emitted_since_reseed).max(1) as usize;
+            let len = (dest.len() - written).min(until_next_reseed);
+
+            self.rng.fill_bytes(&mut dest[written..written + len]);
+            self.emitted_since_reseed += len as u64;
+            written += len;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+}
+
+impl<D: digest::Digest, R: rand_core::RngCore> rand_core::CryptoRng for ReseedingHashRng<D, R> {}
+
+#[cfg(feature = "zeroize")]
+impl<D: digest::Digest, S: udigest::Digestable> Drop for HashRng<D, S> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.buffer.zeroize();
+        self.counter.zeroize();
+        self.offset.zeroize();
+    }
+}
+
+// `Drop` above cannot also scrub `seed`: a `Drop` impl's bounds can't vary with `S`,
+// so it must apply (and behave identically) for every `S`, including ones that don't
+// implement `Zeroize`. There's deliberately no blanket `ZeroizeOnDrop` impl either,
+// since that would claim a guarantee `Drop` doesn't keep for `seed`. When `S` does
+// implement `Zeroize`, call this method to scrub the seed on top of what `Drop` covers.
+#[cfg(feature = "zeroize")]
+impl<D: digest::Digest, S: udigest::Digestable + zeroize::Zeroize> HashRng<D, S> {
+    /// Overwrites the seed and the internal buffer with zeroes
+    ///
+    /// Dropping the generator already scrubs the buffer (and the rest of the
+    /// non-secret state); call this explicitly to additionally scrub the seed, or
+    /// to clear the state sooner than the drop.
+    pub fn zeroize(&mut self) {
+        use zeroize::Zeroize;
+
+        self.seed.zeroize();
+        self.buffer.zeroize();
+        self.counter.zeroize();
+        self.offset.zeroize();
+    }
 }
 
 impl<D: digest::Digest, S: udigest::Digestable> rand_core::RngCore for HashRng<D, S> {
@@ -86,6 +242,65 @@ impl<D: digest::Digest, S: udigest::Digestable> From<S> for HashRng<D, S> {
     }
 }
 
+/// Seeds [`HashRng`] with a fixed-size byte array
+///
+/// This lets [`HashRng`] participate in the standard `rand` seeding ecosystem, e.g.
+/// `HashRng::<D, _>::seed_from_u64(...)` or `HashRng::<D, _>::from_rng(...)`.
+impl<D: digest::Digest> rand_core::SeedableRng for HashRng<D, [u8; 32]> {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_seed(seed)
+    }
+}
+
+/// CSPRNG that streams its output directly from an extendable-output function (XOF)
+///
+/// Unlike [`HashRng`], which re-hashes a fresh digest for every output block, `XofRng`
+/// absorbs the seed once into the XOF and reads randomness directly out of the
+/// resulting [`digest::XofReader`].
+///
+/// See [crate] docs for more details.
+pub struct XofRng<X: digest::ExtendableOutput, S: udigest::Digestable> {
+    reader: X::Reader,
+    _seed: core::marker::PhantomData<S>,
+}
+
+impl<X: digest::ExtendableOutput, S: udigest::Digestable> XofRng<X, S> {
+    /// Constructs randomness generator from the seed
+    pub fn from_seed(seed: S) -> Self
+    where
+        X: Default + digest::Update,
+    {
+        let reader = udigest::hash_xof::<X>(&udigest::inline_struct!("dfns.rand_hash" {
+            seed: &seed,
+        }));
+        Self {
+            reader,
+            _seed: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<X: digest::ExtendableOutput, S: udigest::Digestable> rand_core::RngCore for XofRng<X, S> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        digest::XofReader::read(&mut self.reader, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+}
+
+impl<X: digest::ExtendableOutput, S: udigest::Digestable> rand_core::CryptoRng for XofRng<X, S> {}
+
 pub mod builder {
     //! Alternative way to instantiate `HashRng`
     //!
@@ -94,6 +309,12 @@ pub mod builder {
     //! let rng = rand_hash::builder::with_seed("foobar")
     //!     .with_digest::<sha2::Sha256>();
     //! ```
+    //!
+    //! An extendable-output function can be used instead of a fixed-output digest:
+    //! ```rust
+    //! let rng = rand_hash::builder::with_seed("foobar")
+    //!     .with_xof::<sha3::Shake256>();
+    //! ```
 
     /// Specifies a seed to use
     pub fn with_seed<S>(seed: S) -> WithSeed<S> {
@@ -105,6 +326,11 @@ pub mod builder {
         WithDigest(core::marker::PhantomData)
     }
 
+    /// Specifies an XOF (extendable-output function) to use
+    pub fn with_xof<X>() -> WithXof<X> {
+        WithXof(core::marker::PhantomData)
+    }
+
     /// Builder that holds a seed
     pub struct WithSeed<S> {
         seed: S,
@@ -118,6 +344,15 @@ pub mod builder {
         {
             super::HashRng::<D, S>::from_seed(self.seed)
         }
+
+        /// Specifies a choice of XOF and returns the instance of `XofRng`
+        pub fn with_xof<X>(self) -> super::XofRng<X, S>
+        where
+            X: digest::ExtendableOutput + Default + digest::Update,
+            S: udigest::Digestable,
+        {
+            super::XofRng::<X, S>::from_seed(self.seed)
+        }
     }
 
     /// Builder that holds a choice of digest
@@ -132,13 +367,26 @@ pub mod builder {
             super::HashRng::<D, S>::from_seed(seed)
         }
     }
+
+    /// Builder that holds a choice of XOF
+    pub struct WithXof<X>(core::marker::PhantomData<X>);
+    impl<X> WithXof<X> {
+        /// Specifies a seed to use and returns the instance of `XofRng`
+        pub fn with_seed<S>(&self, seed: S) -> super::XofRng<X, S>
+        where
+            X: digest::ExtendableOutput + Default + digest::Update,
+            S: udigest::Digestable,
+        {
+            super::XofRng::<X, S>::from_seed(seed)
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use rand::{Rng, RngCore};
 
-    use crate::HashRng;
+    use crate::{HashRng, ReseedingHashRng, XofRng};
 
     #[test]
     fn different_window_size() {
@@ -185,4 +433,179 @@ mod tests {
 
         assert_eq!(big_string, concatenation);
     }
+
+    #[test]
+    fn xof_split_large_randomness_in_chunks() {
+        // Same invariant as `split_large_randomness_in_chunks`, but for the
+        // XOF-backed generator: concatenation of many small reads must equal
+        // one big read from the same seed.
+
+        let mut rng = rand_dev::DevRng::new();
+        let seed: [u8; 32] = rng.gen();
+
+        let mut xof_rng = XofRng::<sha3::Shake256, _>::from_seed(seed);
+        let big_len = 20_000;
+        let mut big_string = alloc::vec![0u8; big_len];
+        xof_rng.fill_bytes(&mut big_string);
+
+        let mut xof_rng = XofRng::<sha3::Shake256, _>::from_seed(seed);
+        let mut concatenation = alloc::vec![];
+        while concatenation.len() < big_string.len() {
+            let small_len = rng.gen_range(1..=100.min(big_string.len() - concatenation.len()));
+            let mut small_string = alloc::vec![0u8; small_len];
+            xof_rng.fill_bytes(&mut small_string);
+
+            concatenation.extend_from_slice(&small_string);
+        }
+
+        assert_eq!(big_string, concatenation);
+    }
+
+    #[test]
+    fn set_position_matches_generating_and_discarding() {
+        let mut rng = rand_dev::DevRng::new();
+        let seed: [u8; 32] = rng.gen();
+
+        let mut hash_rng = HashRng::<sha2::Sha256, _>::from_seed(seed);
+
+        let pos = rng.gen_range(0..10_000u64);
+        let mut discarded = alloc::vec![0u8; pos as usize];
+        hash_rng.fill_bytes(&mut discarded);
+        assert_eq!(hash_rng.position(), pos);
+
+        let mut expected = [0u8; 64];
+        hash_rng.fill_bytes(&mut expected);
+
+        let mut hash_rng = HashRng::<sha2::Sha256, _>::from_seed(seed);
+        hash_rng.set_position(pos);
+        assert_eq!(hash_rng.position(), pos);
+
+        let mut actual = [0u8; 64];
+        hash_rng.fill_bytes(&mut actual);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn fork_is_deterministic_and_independent_per_label() {
+        let mut rng = rand_dev::DevRng::new();
+        let seed: [u8; 32] = rng.gen();
+
+        let parent = HashRng::<sha2::Sha256, _>::from_seed(seed);
+
+        let mut child_a1 = parent.fork("a");
+        let mut child_a2 = parent.fork("a");
+        let mut child_b = parent.fork("b");
+
+        let mut out_a1 = [0u8; 64];
+        let mut out_a2 = [0u8; 64];
+        let mut out_b = [0u8; 64];
+        child_a1.fill_bytes(&mut out_a1);
+        child_a2.fill_bytes(&mut out_a2);
+        child_b.fill_bytes(&mut out_b);
+
+        // Same label derives the same child stream
+        assert_eq!(out_a1, out_a2);
+        // Different labels derive independent streams
+        assert_ne!(out_a1, out_b);
+    }
+
+    #[test]
+    fn mix_changes_subsequent_output_but_stays_deterministic() {
+        let mut rng = rand_dev::DevRng::new();
+        let seed: [u8; 32] = rng.gen();
+
+        let mut before_mix = HashRng::<sha2::Sha256, _>::from_seed(seed).fork("reseeding");
+        let mut without_mix = [0u8; 64];
+        before_mix.fill_bytes(&mut without_mix);
+
+        let mut hash_rng = HashRng::<sha2::Sha256, _>::from_seed(seed).fork("reseeding");
+        hash_rng.mix("fresh entropy");
+        let mut with_mix = [0u8; 64];
+        hash_rng.fill_bytes(&mut with_mix);
+        assert_ne!(with_mix, without_mix);
+
+        // Mixing in the same data from the same starting point is deterministic
+        let mut hash_rng_again = HashRng::<sha2::Sha256, _>::from_seed(seed).fork("reseeding");
+        hash_rng_again.mix("fresh entropy");
+        let mut with_mix_again = [0u8; 64];
+        hash_rng_again.fill_bytes(&mut with_mix_again);
+        assert_eq!(with_mix, with_mix_again);
+    }
+
+    #[test]
+    fn reseeding_hash_rng_mixes_in_entropy_past_threshold() {
+        let mut rng = rand_dev::DevRng::new();
+        let seed: [u8; 32] = rng.gen();
+        let entropy_seed_1: [u8; 32] = rng.gen();
+        let entropy_seed_2: [u8; 32] = rng.gen();
+        assert_ne!(entropy_seed_1, entropy_seed_2);
+
+        let inner = HashRng::<sha2::Sha256, _>::from_seed(seed).fork("reseeding-wrapper");
+        let entropy_source = HashRng::<sha2::Sha256, _>::from_seed(entropy_seed_1);
+        let mut reseeding_rng = ReseedingHashRng::new(inner, entropy_source, 8);
+
+        let other_inner = HashRng::<sha2::Sha256, _>::from_seed(seed).fork("reseeding-wrapper");
+        let other_entropy_source = HashRng::<sha2::Sha256, _>::from_seed(entropy_seed_2);
+        let mut other_reseeding_rng = ReseedingHashRng::new(other_inner, other_entropy_source, 8);
+
+        // First 8 bytes come straight from the (identical) inner generators, before
+        // the threshold is crossed
+        let mut before_reseed = [0u8; 8];
+        reseeding_rng.fill_bytes(&mut before_reseed);
+        let mut other_before_reseed = [0u8; 8];
+        other_reseeding_rng.fill_bytes(&mut other_before_reseed);
+        assert_eq!(before_reseed, other_before_reseed);
+
+        // The next read crosses the threshold, triggering a mix-in from each
+        // generator's own (distinct) entropy source, so the output diverges
+        let mut after_reseed = [0u8; 8];
+        reseeding_rng.fill_bytes(&mut after_reseed);
+        let mut other_after_reseed = [0u8; 8];
+        other_reseeding_rng.fill_bytes(&mut other_after_reseed);
+        assert_ne!(after_reseed, other_after_reseed);
+    }
+
+    #[test]
+    fn reseeding_hash_rng_reseed_is_independent_of_read_granularity() {
+        // A single large read that crosses `threshold` several times must reseed at
+        // every crossing, same as splitting the same amount of randomness into many
+        // smaller reads each bounded by `threshold`.
+        let mut rng = rand_dev::DevRng::new();
+        let seed: [u8; 32] = rng.gen();
+        let entropy_seed: [u8; 32] = rng.gen();
+
+        let make = || {
+            let inner = HashRng::<sha2::Sha256, _>::from_seed(seed).fork("reseeding-granularity");
+            let entropy_source = HashRng::<sha2::Sha256, _>::from_seed(entropy_seed);
+            ReseedingHashRng::new(inner, entropy_source, 8)
+        };
+
+        let mut one_big_read = make();
+        let mut big_string = [0u8; 100];
+        one_big_read.fill_bytes(&mut big_string);
+
+        let mut many_small_reads = make();
+        let mut concatenation = alloc::vec![];
+        while concatenation.len() < big_string.len() {
+            let small_len = rng.gen_range(1..=8.min(big_string.len() - concatenation.len()));
+            let mut small_string = alloc::vec![0u8; small_len];
+            many_small_reads.fill_bytes(&mut small_string);
+            concatenation.extend_from_slice(&small_string);
+        }
+
+        assert_eq!(&big_string[..], &concatenation[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize_clears_seed_and_buffer() {
+        let mut rng = HashRng::<sha2::Sha256, _>::from_seed([1u8; 32]);
+        rng.zeroize();
+
+        assert_eq!(rng.seed, [0u8; 32]);
+        assert_eq!(rng.buffer, Default::default());
+        assert_eq!(rng.counter, 0);
+        assert_eq!(rng.offset, 0);
+    }
 }